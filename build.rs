@@ -0,0 +1,18 @@
+fn main() {
+    // The BigQuery Storage Read API client is only needed (and only compiled) behind the
+    // `storage` feature, since it pulls in tonic/prost and an Arrow decoder.
+    #[cfg(feature = "storage")]
+    build_storage_proto();
+}
+
+#[cfg(feature = "storage")]
+fn build_storage_proto() {
+    std::env::set_var("PROTOC", protobuf_src::protoc());
+
+    println!("cargo:rerun-if-changed=proto/bigquery_storage.proto");
+
+    tonic_build::configure()
+        .build_server(false)
+        .compile_protos(&["proto/bigquery_storage.proto"], &["proto"])
+        .expect("failed to compile proto/bigquery_storage.proto");
+}
@@ -16,6 +16,21 @@ pub enum BQError {
     #[error(transparent)]
     SerializationError(#[from] serde_json::Error),
 
+    /// Establishing the gRPC channel to the BigQuery Storage Read API failed.
+    #[cfg(feature = "storage")]
+    #[error(transparent)]
+    StorageTransportError(#[from] tonic::transport::Error),
+
+    /// A gRPC call to the BigQuery Storage Read API returned an error status.
+    #[cfg(feature = "storage")]
+    #[error(transparent)]
+    StorageStatusError(#[from] tonic::Status),
+
+    /// Decoding an Arrow IPC schema or record batch from a Storage Read API response failed.
+    #[cfg(feature = "storage")]
+    #[error(transparent)]
+    ArrowError(#[from] arrow::error::ArrowError),
+
     /// Exchanging the service account key for an access token failed.
     #[error("authentication error: {0}")]
     AuthError(String),
@@ -1,4 +1,8 @@
 //! Manage BigQuery jobs.
+use std::time::Instant;
+
+use async_stream::try_stream;
+use futures::{Stream, StreamExt};
 use reqwest::Client;
 
 use crate::auth::ServiceAccountAuthenticator;
@@ -7,12 +11,19 @@ use crate::model::get_query_results_parameters::GetQueryResultsParameters;
 use crate::model::get_query_results_response::GetQueryResultsResponse;
 use crate::model::job::Job;
 use crate::model::job_cancel_response::JobCancelResponse;
+use crate::model::job_configuration::JobConfiguration;
+use crate::model::job_configuration_extract::JobConfigurationExtract;
+use crate::model::job_configuration_load::JobConfigurationLoad;
 use crate::model::job_list::JobList;
+use crate::model::poll_options::PollOptions;
 use crate::model::query_request::QueryRequest;
 use crate::model::query_response::{QueryResponse, ResultSet};
+use crate::model::query_statistics::QueryStatistics;
+use crate::model::table_row::TableRow;
 use crate::{process_response, urlencode};
 
 /// A job API handler.
+#[derive(Clone)]
 pub struct JobApi {
     client: Client,
     sa_auth: ServiceAccountAuthenticator,
@@ -201,19 +212,229 @@ impl JobApi {
 
         process_response(resp).await
     }
+
+    /// Blocks until an asynchronous job (started via [`JobApi::insert`]) reaches the `DONE`
+    /// state, polling [`JobApi::get_job`] with capped exponential backoff.
+    ///
+    /// Returns the finished `Job` once `status.state == "DONE"`, or an error if the job failed
+    /// (`status.error_result` was set) or if `poll_options.overall_timeout` elapses first.
+    /// # Arguments
+    /// * `project_id` - Project ID of the requested job.
+    /// * `job_id` - Job ID of the requested job.
+    /// * `location` - The geographic location of the job. Required except for US and EU. See
+    ///   details at https://cloud.google.com/bigquery/docs/locations#specifying_your_location.
+    /// * `poll_options` - Controls the initial/max poll interval, backoff multiplier and overall deadline.
+    pub async fn wait_for_job(
+        &self,
+        project_id: &str,
+        job_id: &str,
+        location: Option<&str>,
+        poll_options: PollOptions,
+    ) -> Result<Job, BQError> {
+        let deadline = Instant::now() + poll_options.overall_timeout;
+        let mut interval = poll_options.initial_interval;
+
+        loop {
+            let job = self.get_job(project_id, job_id, location).await?;
+
+            if let Some(status) = &job.status {
+                if status.state.as_deref() == Some("DONE") {
+                    if let Some(error_result) = &status.error_result {
+                        return Err(BQError::JobFailed(format!(
+                            "job {job_id} failed: {error_result:?}"
+                        )));
+                    }
+                    return Ok(job);
+                }
+            }
+
+            if Instant::now() >= deadline {
+                return Err(BQError::JobPollingTimeout(format!(
+                    "timed out waiting for job {job_id} to reach DONE after {:?}",
+                    poll_options.overall_timeout
+                )));
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            tokio::time::sleep(std::cmp::min(interval, remaining)).await;
+            interval = std::cmp::min(interval.mul_f64(poll_options.multiplier), poll_options.max_interval);
+        }
+    }
+
+    /// Starts a load job that ingests data from Cloud Storage into a BigQuery table.
+    /// # Arguments
+    /// * `project_id` - Project ID of project that will be billed for the job.
+    /// * `config` - The load job configuration, built via [`JobConfigurationLoad`].
+    pub async fn load_from_gcs(&self, project_id: &str, config: JobConfigurationLoad) -> Result<Job, BQError> {
+        let job = Job {
+            configuration: Some(JobConfiguration {
+                load: Some(config),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        self.insert(project_id, job).await
+    }
+
+    /// Starts an extract job that exports a BigQuery table to Cloud Storage.
+    /// # Arguments
+    /// * `project_id` - Project ID of project that will be billed for the job.
+    /// * `config` - The extract job configuration, built via [`JobConfigurationExtract`].
+    pub async fn extract_to_gcs(&self, project_id: &str, config: JobConfigurationExtract) -> Result<Job, BQError> {
+        let job = Job {
+            configuration: Some(JobConfiguration {
+                extract: Some(config),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        self.insert(project_id, job).await
+    }
+
+    /// Streams every row of a finished query job's result set, transparently following
+    /// `pageToken` across [`JobApi::get_query_results`] calls so huge result sets can be
+    /// processed with bounded memory.
+    /// # Arguments
+    /// * `project_id` - Project ID of the query request.
+    /// * `job_id` - Job ID of the query job.
+    /// * `parameters` - The query parameters for jobs.getQueryResults. Any `page_token` already set is honored as the starting page.
+    pub fn stream_query_results<'a>(
+        &'a self,
+        project_id: &'a str,
+        job_id: &'a str,
+        parameters: GetQueryResultsParameters,
+    ) -> impl Stream<Item = Result<TableRow, BQError>> + 'a {
+        try_stream! {
+            let mut parameters = parameters;
+
+            loop {
+                let response = self.get_query_results(project_id, job_id, parameters.clone()).await?;
+
+                if let Some(rows) = response.rows {
+                    for row in rows {
+                        yield row;
+                    }
+                }
+
+                match response.page_token {
+                    Some(page_token) if !page_token.is_empty() => parameters.page_token = Some(page_token),
+                    _ => break,
+                }
+            }
+        }
+    }
+
+    /// Runs a `QueryRequest` and streams every row of its result set, transparently following
+    /// `pageToken` across pages once the initial response is exhausted.
+    /// # Arguments
+    /// * `project_id` - Project ID of the query request.
+    /// * `query_request` - The request body contains an instance of QueryRequest.
+    pub fn stream_query<'a>(
+        &'a self,
+        project_id: &'a str,
+        query_request: QueryRequest,
+    ) -> impl Stream<Item = Result<TableRow, BQError>> + 'a {
+        try_stream! {
+            let req_url = format!(
+                "https://bigquery.googleapis.com/bigquery/v2/projects/{project_id}/queries",
+                project_id = urlencode(project_id)
+            );
+
+            let access_token = self.sa_auth.access_token().await?;
+
+            let request = self
+                .client
+                .post(req_url.as_str())
+                .bearer_auth(access_token)
+                .json(&query_request)
+                .build()?;
+
+            let resp = self.client.execute(request).await?;
+            let query_response: QueryResponse = process_response(resp).await?;
+
+            if let Some(rows) = query_response.rows {
+                for row in rows {
+                    yield row;
+                }
+            }
+
+            let job_id = query_response
+                .job_reference
+                .as_ref()
+                .and_then(|job_reference| job_reference.job_id.clone());
+
+            if let (Some(job_id), Some(page_token)) = (job_id, query_response.page_token) {
+                if !page_token.is_empty() {
+                    let parameters = GetQueryResultsParameters {
+                        page_token: Some(page_token),
+                        ..Default::default()
+                    };
+
+                    let mut rest = Box::pin(self.stream_query_results(project_id, &job_id, parameters));
+                    while let Some(row) = rest.next().await {
+                        yield row?;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Estimates the cost of a query without running it, by issuing it as a dry run. Returns
+    /// the bytes BigQuery would scan (and whether the result would come from cache) so callers
+    /// can gate expensive queries behind a byte-budget check.
+    /// # Arguments
+    /// * `project_id` - Project ID of the query request.
+    /// * `query_request` - The request body contains an instance of QueryRequest.
+    pub async fn estimate_query(
+        &self,
+        project_id: &str,
+        query_request: QueryRequest,
+    ) -> Result<QueryStatistics, BQError> {
+        let req_url = format!(
+            "https://bigquery.googleapis.com/bigquery/v2/projects/{project_id}/queries",
+            project_id = urlencode(project_id)
+        );
+
+        let access_token = self.sa_auth.access_token().await?;
+
+        let dry_run_request = QueryRequest {
+            dry_run: true,
+            ..query_request
+        };
+
+        let request = self
+            .client
+            .post(req_url.as_str())
+            .bearer_auth(access_token)
+            .json(&dry_run_request)
+            .build()?;
+
+        let resp = self.client.execute(request).await?;
+
+        let query_response: QueryResponse = process_response(resp).await?;
+        Ok(QueryStatistics::from(query_response))
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use futures::TryStreamExt;
     use serde::Serialize;
 
     use crate::error::BQError;
     use crate::model::dataset::Dataset;
+    use crate::model::job_configuration_extract::JobConfigurationExtract;
+    use crate::model::job_configuration_load::JobConfigurationLoad;
+    use crate::model::poll_options::PollOptions;
     use crate::model::query_request::QueryRequest;
     use crate::model::query_response::{QueryResponse, ResultSet};
     use crate::model::table::Table;
     use crate::model::table_data_insert_all_request::TableDataInsertAllRequest;
     use crate::model::table_field_schema::TableFieldSchema;
+    use crate::model::table_reference::TableReference;
+    use crate::model::table_row::TableRow;
     use crate::model::table_schema::TableSchema;
     use crate::{env_vars, Client};
 
@@ -241,7 +462,7 @@ mod test {
 
     #[tokio::test]
     async fn test() -> Result<(), BQError> {
-        let (ref project_id, ref dataset_id, ref table_id, ref sa_key) = env_vars();
+        let (ref project_id, ref dataset_id, ref table_id, ref sa_key, ref gcs_bucket) = env_vars();
         let dataset_id = &format!("{}_job", dataset_id);
 
         let client = Client::from_service_account_key_file(sa_key).await;
@@ -254,31 +475,27 @@ mod test {
         assert_eq!(created_dataset.id, Some(format!("{}:{}", project_id, dataset_id)));
 
         // Create table
-        let table = Table::new(
-            project_id,
-            dataset_id,
-            table_id,
-            TableSchema::new(vec![
-                TableFieldSchema::integer("int_value"),
-                TableFieldSchema::float("float_value"),
-                TableFieldSchema::bool("bool_value"),
-                TableFieldSchema::string("string_value"),
-                TableFieldSchema::record(
-                    "record_value",
-                    vec![
-                        TableFieldSchema::integer("int_value"),
-                        TableFieldSchema::string("string_value"),
-                        TableFieldSchema::record(
-                            "record_value",
-                            vec![
-                                TableFieldSchema::integer("int_value"),
-                                TableFieldSchema::string("string_value"),
-                            ],
-                        ),
-                    ],
-                ),
-            ]),
-        );
+        let table_schema = TableSchema::new(vec![
+            TableFieldSchema::integer("int_value"),
+            TableFieldSchema::float("float_value"),
+            TableFieldSchema::bool("bool_value"),
+            TableFieldSchema::string("string_value"),
+            TableFieldSchema::record(
+                "record_value",
+                vec![
+                    TableFieldSchema::integer("int_value"),
+                    TableFieldSchema::string("string_value"),
+                    TableFieldSchema::record(
+                        "record_value",
+                        vec![
+                            TableFieldSchema::integer("int_value"),
+                            TableFieldSchema::string("string_value"),
+                        ],
+                    ),
+                ],
+            ),
+        ]);
+        let table = Table::new(project_id, dataset_id, table_id, table_schema.clone());
 
         let created_table = client.table().create(table).await?;
         assert_eq!(created_table.table_reference.table_id, table_id.to_string());
@@ -361,17 +578,20 @@ mod test {
 
         assert!(result.is_ok(), "{:?}", result);
 
-        // Query
-        let mut rs = client
-            .job()
-            .query(
-                project_id,
-                QueryRequest::new(format!(
-                    "SELECT COUNT(*) AS c FROM `{}.{}.{}`",
-                    project_id, dataset_id, table_id
-                )),
-            )
+        let query_request = QueryRequest::new(format!(
+            "SELECT COUNT(*) AS c FROM `{}.{}.{}`",
+            project_id, dataset_id, table_id
+        ));
+
+        // Estimate the query's cost via a dry run before actually running it
+        let estimate = client
+            .job_api
+            .estimate_query(project_id, query_request.clone())
             .await?;
+        assert!(estimate.total_bytes_processed.is_some());
+
+        // Query
+        let mut rs = client.job().query(project_id, query_request.clone()).await?;
         while rs.next_row() {
             assert!(rs.get_i64_by_name("c")?.is_some());
         }
@@ -386,7 +606,10 @@ mod test {
             .clone()
             .expect("expected job_id");
 
-        let job = client.job_api.get_job(project_id, &job_id, None).await?;
+        let job = client
+            .job_api
+            .wait_for_job(project_id, &job_id, None, PollOptions::default())
+            .await?;
         assert_eq!(job.status.unwrap().state.unwrap(), "DONE");
 
         // GetQueryResults
@@ -400,6 +623,71 @@ mod test {
             assert!(rs.get_i64_by_name("c")?.is_some());
         }
 
+        // Stream every row of the already-finished query job's result set
+        let streamed_rows: Vec<TableRow> = client
+            .job_api
+            .stream_query_results(project_id, &job_id, Default::default())
+            .try_collect()
+            .await?;
+        assert_eq!(streamed_rows.len(), rs.row_count());
+
+        // Run a fresh query and stream its result set directly
+        let streamed_query_rows: Vec<TableRow> =
+            client.job_api.stream_query(project_id, query_request).try_collect().await?;
+        assert_eq!(streamed_query_rows.len(), rs.row_count());
+
+        // Round-trip the table through Cloud Storage: export it, then load it back into a
+        // second table and confirm the row count survived the trip.
+        let gcs_uri = format!("gs://{gcs_bucket}/{dataset_id}/{table_id}/data-*.json");
+        let reload_table_id = &format!("{table_id}_reload");
+
+        let extract_job = client
+            .job_api
+            .extract_to_gcs(
+                project_id,
+                JobConfigurationExtract::new(
+                    TableReference::new(project_id, dataset_id, table_id),
+                    vec![gcs_uri.clone()],
+                )
+                .with_destination_format("NEWLINE_DELIMITED_JSON"),
+            )
+            .await?;
+        let extract_job_id = extract_job.job_reference.expect("expected job_reference").job_id.expect("expected job_id");
+        client
+            .job_api
+            .wait_for_job(project_id, &extract_job_id, None, PollOptions::default())
+            .await?;
+
+        let load_job = client
+            .job_api
+            .load_from_gcs(
+                project_id,
+                JobConfigurationLoad::newline_delimited_json(
+                    vec![gcs_uri],
+                    TableReference::new(project_id, dataset_id, reload_table_id),
+                )
+                .with_schema(table_schema),
+            )
+            .await?;
+        let load_job_id = load_job.job_reference.expect("expected job_reference").job_id.expect("expected job_id");
+        client
+            .job_api
+            .wait_for_job(project_id, &load_job_id, None, PollOptions::default())
+            .await?;
+
+        let mut reloaded_rs = client
+            .job()
+            .query(
+                project_id,
+                QueryRequest::new(format!(
+                    "SELECT COUNT(*) AS c FROM `{project_id}.{dataset_id}.{reload_table_id}`"
+                )),
+            )
+            .await?;
+        assert!(reloaded_rs.next_row());
+        assert_eq!(reloaded_rs.get_i64_by_name("c")?, Some(4));
+
+        client.table().delete(project_id, dataset_id, reload_table_id).await?;
         client.table().delete(project_id, dataset_id, table_id).await?;
 
         // Delete dataset
@@ -104,14 +104,15 @@ where
     }
 }
 
-/// Reads the `PROJECT_ID`/`DATASET_ID`/`TABLE_ID`/`SERVICE_ACCOUNT_KEY` environment variables
-/// used by this crate's integration tests.
+/// Reads the `PROJECT_ID`/`DATASET_ID`/`TABLE_ID`/`SERVICE_ACCOUNT_KEY`/`GCS_BUCKET` environment
+/// variables used by this crate's integration tests.
 #[cfg(test)]
-pub(crate) fn env_vars() -> (String, String, String, String) {
+pub(crate) fn env_vars() -> (String, String, String, String, String) {
     (
         std::env::var("PROJECT_ID").expect("PROJECT_ID env var not set"),
         std::env::var("DATASET_ID").expect("DATASET_ID env var not set"),
         std::env::var("TABLE_ID").expect("TABLE_ID env var not set"),
         std::env::var("SERVICE_ACCOUNT_KEY").expect("SERVICE_ACCOUNT_KEY env var not set"),
+        std::env::var("GCS_BUCKET").expect("GCS_BUCKET env var not set"),
     )
 }
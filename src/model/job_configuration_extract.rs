@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::table_reference::TableReference;
+
+/// Configures an extract job, which exports a BigQuery table to Cloud Storage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobConfigurationExtract {
+    /// The table to export.
+    pub source_table: TableReference,
+    /// The fully-qualified `gs://` URIs the exported data is written to.
+    pub destination_uris: Vec<String>,
+    /// The format of the exported files, e.g. `NEWLINE_DELIMITED_JSON`, `CSV`, `AVRO` or `PARQUET`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_format: Option<String>,
+    /// The compression applied to the exported files, e.g. `GZIP`, `SNAPPY` or `NONE`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compression: Option<String>,
+}
+
+impl JobConfigurationExtract {
+    pub fn new(source_table: TableReference, destination_uris: Vec<String>) -> Self {
+        Self {
+            source_table,
+            destination_uris,
+            destination_format: None,
+            compression: None,
+        }
+    }
+
+    /// Sets the destination format (e.g. `NEWLINE_DELIMITED_JSON`, `CSV`, `AVRO`, `PARQUET`).
+    pub fn with_destination_format(mut self, destination_format: impl Into<String>) -> Self {
+        self.destination_format = Some(destination_format.into());
+        self
+    }
+
+    /// Sets the compression applied to the exported files (e.g. `GZIP`, `SNAPPY`, `NONE`).
+    pub fn with_compression(mut self, compression: impl Into<String>) -> Self {
+        self.compression = Some(compression.into());
+        self
+    }
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::table_reference::TableReference;
+use crate::model::table_schema::TableSchema;
+
+/// Configures a load job, which ingests data from Cloud Storage into a BigQuery table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobConfigurationLoad {
+    /// The fully-qualified `gs://` URIs of the source files to load.
+    pub source_uris: Vec<String>,
+    /// The destination table to load the data into.
+    pub destination_table: TableReference,
+    /// The schema of the destination table, required unless the table already exists or schema
+    /// auto-detection is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<TableSchema>,
+    /// The format of the source files, e.g. `NEWLINE_DELIMITED_JSON`, `CSV`, `AVRO` or `PARQUET`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_format: Option<String>,
+    /// Specifies the action that occurs if the destination table already exists, e.g.
+    /// `WRITE_TRUNCATE`, `WRITE_APPEND` or `WRITE_EMPTY`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_disposition: Option<String>,
+    /// Specifies whether the destination table must already exist, e.g. `CREATE_IF_NEEDED` or
+    /// `CREATE_NEVER`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_disposition: Option<String>,
+}
+
+impl JobConfigurationLoad {
+    fn new(source_format: &str, source_uris: Vec<String>, destination_table: TableReference) -> Self {
+        Self {
+            source_uris,
+            destination_table,
+            schema: None,
+            source_format: Some(source_format.to_string()),
+            write_disposition: None,
+            create_disposition: None,
+        }
+    }
+
+    /// Loads newline-delimited JSON files.
+    pub fn newline_delimited_json(source_uris: Vec<String>, destination_table: TableReference) -> Self {
+        Self::new("NEWLINE_DELIMITED_JSON", source_uris, destination_table)
+    }
+
+    /// Loads CSV files.
+    pub fn csv(source_uris: Vec<String>, destination_table: TableReference) -> Self {
+        Self::new("CSV", source_uris, destination_table)
+    }
+
+    /// Loads Avro files.
+    pub fn avro(source_uris: Vec<String>, destination_table: TableReference) -> Self {
+        Self::new("AVRO", source_uris, destination_table)
+    }
+
+    /// Loads Parquet files.
+    pub fn parquet(source_uris: Vec<String>, destination_table: TableReference) -> Self {
+        Self::new("PARQUET", source_uris, destination_table)
+    }
+
+    /// Sets the destination table schema.
+    pub fn with_schema(mut self, schema: TableSchema) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Sets the write disposition (e.g. `WRITE_TRUNCATE`, `WRITE_APPEND`, `WRITE_EMPTY`).
+    pub fn with_write_disposition(mut self, write_disposition: impl Into<String>) -> Self {
+        self.write_disposition = Some(write_disposition.into());
+        self
+    }
+
+    /// Sets the create disposition (e.g. `CREATE_IF_NEEDED`, `CREATE_NEVER`).
+    pub fn with_create_disposition(mut self, create_disposition: impl Into<String>) -> Self {
+        self.create_disposition = Some(create_disposition.into());
+        self
+    }
+}
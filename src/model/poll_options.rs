@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+/// Tuning knobs for [`JobApi::wait_for_job`](crate::job::JobApi::wait_for_job)'s polling loop.
+///
+/// The poller starts at `initial_interval` and doubles the delay after every poll (scaled by
+/// `multiplier`), capping it at `max_interval`, until the job reaches `DONE` or
+/// `overall_timeout` elapses.
+#[derive(Debug, Clone, Copy)]
+pub struct PollOptions {
+    /// Delay before the first re-poll.
+    pub initial_interval: Duration,
+    /// Upper bound the backoff delay is capped at.
+    pub max_interval: Duration,
+    /// Factor the delay is multiplied by after every poll.
+    pub multiplier: f64,
+    /// Total time budget before `wait_for_job` gives up with a timeout error.
+    pub overall_timeout: Duration,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(30),
+            multiplier: 2.0,
+            overall_timeout: Duration::from_secs(10 * 60),
+        }
+    }
+}
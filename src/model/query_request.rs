@@ -6,6 +6,14 @@ use serde::{Deserialize, Serialize};
 pub struct QueryRequest {
     pub query: String,
     pub use_legacy_sql: bool,
+    /// If set, BigQuery estimates the cost of running the query without actually running it.
+    /// Set via [`JobApi::estimate_query`](crate::job::JobApi::estimate_query).
+    #[serde(skip_serializing_if = "is_false")]
+    pub dry_run: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
 }
 
 impl QueryRequest {
@@ -13,6 +21,7 @@ impl QueryRequest {
         Self {
             query: query.into(),
             use_legacy_sql: false,
+            dry_run: false,
         }
     }
 }
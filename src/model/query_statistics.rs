@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::query_response::QueryResponse;
+
+/// Cost/size statistics for a query, obtained without actually running it via a dry run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryStatistics {
+    /// The total number of bytes the query would process if it were run for real.
+    pub total_bytes_processed: Option<String>,
+    /// Whether the query results would be served from the query cache.
+    pub cache_hit: Option<bool>,
+}
+
+impl From<QueryResponse> for QueryStatistics {
+    fn from(query_response: QueryResponse) -> Self {
+        Self {
+            total_bytes_processed: query_response.total_bytes_processed,
+            cache_hit: query_response.cache_hit,
+        }
+    }
+}
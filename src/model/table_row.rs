@@ -1,7 +1,7 @@
 use serde::{Serialize, Deserialize};
 use crate::model::table_cell::TableCell;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TableRow {
     /// Represents a single row in the result set, consisting of one or more fields.
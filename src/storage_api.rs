@@ -0,0 +1,247 @@
+//! High-throughput table reads via the BigQuery Storage Read API, decoded as Arrow record
+//! batches instead of row-by-row `TableCell` parsing. Requires the `storage` feature.
+#![cfg(feature = "storage")]
+
+use std::sync::Arc;
+
+use arrow::ipc::convert::try_schema_from_ipc_buffer;
+use arrow::ipc::reader::read_record_batch;
+use arrow::record_batch::RecordBatch;
+use async_stream::try_stream;
+use futures::Stream;
+use tonic::codegen::InterceptedService;
+use tonic::service::Interceptor;
+use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
+use tonic::{Request, Status};
+
+use crate::auth::ServiceAccountAuthenticator;
+use crate::error::BQError;
+
+#[allow(clippy::all)]
+pub mod storage_proto {
+    tonic::include_proto!("google.cloud.bigquery.storage.v1");
+}
+
+use storage_proto::big_query_read_client::BigQueryReadClient;
+use storage_proto::read_rows_response::Rows;
+use storage_proto::read_session::{DataFormat, TableReadOptions};
+use storage_proto::{CreateReadSessionRequest, ReadRowsRequest, ReadSession};
+
+const STORAGE_ENDPOINT: &str = "https://bigquerystorage.googleapis.com";
+
+#[derive(Clone)]
+struct AuthInterceptor {
+    access_token: String,
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let value = format!("Bearer {}", self.access_token)
+            .parse()
+            .map_err(|_| Status::unauthenticated("invalid access token"))?;
+        request.metadata_mut().insert("authorization", value);
+        Ok(request)
+    }
+}
+
+/// A BigQuery Storage Read API handler, for reading table data as Arrow record batches far
+/// faster than the REST `tabledata`/`getQueryResults` path.
+pub struct StorageApi {
+    sa_auth: ServiceAccountAuthenticator,
+    channel: Channel,
+}
+
+impl StorageApi {
+    pub(crate) async fn new(sa_auth: ServiceAccountAuthenticator) -> Result<Self, BQError> {
+        let channel = Endpoint::from_static(STORAGE_ENDPOINT)
+            .tls_config(ClientTlsConfig::new())?
+            .connect()
+            .await?;
+
+        Ok(Self { sa_auth, channel })
+    }
+
+    async fn client(&self) -> Result<BigQueryReadClient<InterceptedService<Channel, AuthInterceptor>>, BQError> {
+        let access_token = self.sa_auth.access_token().await?;
+
+        Ok(BigQueryReadClient::with_interceptor(
+            self.channel.clone(),
+            AuthInterceptor { access_token },
+        ))
+    }
+
+    /// Creates a read session for `table`, optionally projecting `selected_fields` and filtering
+    /// with `row_restriction`, split into up to `max_stream_count` parallel streams. Each stream
+    /// in the returned session can then be read with [`StorageApi::read_stream`].
+    /// # Arguments
+    /// * `project_id` - Project ID billed for the read session.
+    /// * `table` - Fully-qualified table resource name to read, e.g. `projects/p/datasets/d/tables/t`.
+    /// * `selected_fields` - Column projection; empty reads every column.
+    /// * `row_restriction` - A SQL-like filter predicate evaluated server-side.
+    /// * `max_stream_count` - Upper bound on the number of streams the session is split into.
+    pub async fn create_read_session(
+        &self,
+        project_id: &str,
+        table: &str,
+        selected_fields: Vec<String>,
+        row_restriction: Option<String>,
+        max_stream_count: i32,
+    ) -> Result<ReadSession, BQError> {
+        let read_session = ReadSession {
+            table: table.to_string(),
+            data_format: DataFormat::Arrow as i32,
+            read_options: Some(TableReadOptions {
+                selected_fields,
+                row_restriction: row_restriction.unwrap_or_default(),
+            }),
+            ..Default::default()
+        };
+
+        let request = CreateReadSessionRequest {
+            parent: format!("projects/{project_id}"),
+            read_session: Some(read_session),
+            max_stream_count,
+        };
+
+        let response = self.client().await?.create_read_session(Request::new(request)).await?;
+        Ok(response.into_inner())
+    }
+
+    /// Reads a single stream of a read session as a lazy sequence of decoded Arrow record
+    /// batches, so callers can feed BigQuery data directly into columnar/analytics tooling.
+    ///
+    /// Each `ReadRowsResponse` only carries a batch's row data; the schema is sent once, on the
+    /// `ReadSession` itself, so `session` must be the one `read_stream` was created from.
+    /// # Arguments
+    /// * `session` - The read session `read_stream` belongs to, as returned by [`StorageApi::create_read_session`].
+    /// * `read_stream` - The `ReadStream::name` of the stream to read.
+    pub fn read_stream<'a>(
+        &'a self,
+        session: &'a ReadSession,
+        read_stream: &'a str,
+    ) -> impl Stream<Item = Result<RecordBatch, BQError>> + 'a {
+        try_stream! {
+            let schema_bytes = &session
+                .arrow_schema
+                .as_ref()
+                .ok_or_else(|| BQError::InvalidReadSession("read session is missing an Arrow schema".to_string()))?
+                .serialized_schema;
+            let schema = Arc::new(try_schema_from_ipc_buffer(schema_bytes)?);
+
+            let mut client = self.client().await?;
+            let request = ReadRowsRequest {
+                read_stream: read_stream.to_string(),
+                offset: 0,
+            };
+
+            let mut responses = client.read_rows(Request::new(request)).await?.into_inner();
+
+            while let Some(response) = responses.message().await? {
+                if let Some(Rows::ArrowRecordBatch(batch)) = response.rows {
+                    let buffer = batch.serialized_record_batch.as_slice();
+                    let message = arrow::ipc::root_as_message(buffer).map_err(|err| {
+                        BQError::InvalidReadSession(format!("invalid Arrow IPC record batch message: {err}"))
+                    })?;
+                    let ipc_batch = message.header_as_record_batch().ok_or_else(|| {
+                        BQError::InvalidReadSession("Arrow IPC message was not a RecordBatch".to_string())
+                    })?;
+
+                    let record_batch = read_record_batch(
+                        &buffer.into(),
+                        ipc_batch,
+                        schema.clone(),
+                        &Default::default(),
+                        None,
+                        &message.version(),
+                    )?;
+                    yield record_batch;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::TryStreamExt;
+    use serde::Serialize;
+
+    use crate::error::BQError;
+    use crate::model::dataset::Dataset;
+    use crate::model::table::Table;
+    use crate::model::table_data_insert_all_request::TableDataInsertAllRequest;
+    use crate::model::table_field_schema::TableFieldSchema;
+    use crate::model::table_schema::TableSchema;
+    use crate::{env_vars, Client};
+
+    #[derive(Serialize)]
+    struct MyRow {
+        int_value: i64,
+        string_value: String,
+    }
+
+    #[tokio::test]
+    async fn test() -> Result<(), BQError> {
+        let (ref project_id, ref dataset_id, ref table_id, ref sa_key, _) = env_vars();
+        let dataset_id = &format!("{}_storage", dataset_id);
+
+        let client = Client::from_service_account_key_file(sa_key).await;
+
+        client.table().delete_if_exists(project_id, dataset_id, table_id).await;
+        client.dataset().delete_if_exists(project_id, dataset_id, true).await;
+
+        // Create dataset
+        client.dataset().create(Dataset::new(project_id, dataset_id)).await?;
+
+        // Create table
+        let table = Table::new(
+            project_id,
+            dataset_id,
+            table_id,
+            TableSchema::new(vec![
+                TableFieldSchema::integer("int_value"),
+                TableFieldSchema::string("string_value"),
+            ]),
+        );
+        client.table().create(table).await?;
+
+        // Insert data
+        let mut insert_request = TableDataInsertAllRequest::new();
+        for i in 0..4 {
+            insert_request.add_row(
+                None,
+                MyRow {
+                    int_value: i,
+                    string_value: format!("row-{i}"),
+                },
+            )?;
+        }
+        client
+            .tabledata()
+            .insert_all(project_id, dataset_id, table_id, insert_request)
+            .await?;
+
+        // Create a read session over the whole table and read every stream back as Arrow
+        // record batches, confirming the session/stream wiring and the IPC decode path work
+        // end to end against a real BigQuery Storage Read API backend.
+        let storage = client.storage().await?;
+        let table_resource = format!("projects/{project_id}/datasets/{dataset_id}/tables/{table_id}");
+        let session = storage
+            .create_read_session(project_id, &table_resource, vec![], None, 1)
+            .await?;
+
+        let mut row_count = 0;
+        for stream in &session.streams {
+            let batches: Vec<_> = storage.read_stream(&session, &stream.name).try_collect().await?;
+            row_count += batches.iter().map(|batch| batch.num_rows()).sum::<usize>();
+        }
+        assert_eq!(row_count, 4);
+
+        client.table().delete(project_id, dataset_id, table_id).await?;
+
+        // Delete dataset
+        client.dataset().delete(project_id, dataset_id, true).await?;
+
+        Ok(())
+    }
+}